@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use eyre::Result;
+
+use super::ConsensusRpc;
+use crate::types::*;
+
+/// Separator between the inner RPC's endpoint and the local store path packed into the
+/// single `rpc` string.
+const STORE_SEP: char = '|';
+
+/// Caches `get_updates` results in a local `sled` store, keyed by sync-committee period,
+/// one canonical best update per period — mirroring a beacon node's
+/// `get_light_client_update(sync_committee_period)`.
+pub struct CachedRpc<R: ConsensusRpc> {
+    inner: R,
+    db: sled::Db,
+}
+
+impl<R: ConsensusRpc + Send + Sync> CachedRpc<R> {
+    pub fn with_store(inner: R, db: sled::Db) -> Self {
+        CachedRpc { inner, db }
+    }
+
+    fn key(period: u64) -> [u8; 8] {
+        period.to_be_bytes()
+    }
+
+    fn load(&self, period: u64) -> Result<Option<Update>> {
+        match self.db.get(Self::key(period))? {
+            Some(bytes) => Ok(Some(bincode::deserialize(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn store(&self, period: u64, update: &Update) -> Result<()> {
+        let bytes = bincode::serialize(update)?;
+        self.db.insert(Self::key(period), bytes)?;
+        Ok(())
+    }
+
+    /// Only overwrites the cached entry if `update` is strictly better, so an early,
+    /// weakly-attested update for the current period isn't pinned forever.
+    fn store_if_better(&self, period: u64, update: &Update) -> Result<()> {
+        if let Some(existing) = self.load(period)? {
+            if !is_better_update(update, &existing) {
+                return Ok(());
+            }
+        }
+
+        self.store(period, update)
+    }
+}
+
+/// More attesting sync committee participation wins; ties break on the more recent slot.
+fn is_better_update(new: &Update, old: &Update) -> bool {
+    let bits = |u: &Update| -> usize {
+        u.sync_aggregate
+            .sync_committee_bits
+            .iter()
+            .filter(|bit| **bit)
+            .count()
+    };
+
+    match bits(new).cmp(&bits(old)) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => new.signature_slot > old.signature_slot,
+    }
+}
+
+#[async_trait]
+impl<R: ConsensusRpc + Send + Sync> ConsensusRpc for CachedRpc<R> {
+    fn new(rpc: &str) -> Self {
+        let (url, store_path) = rpc
+            .split_once(STORE_SEP)
+            .expect("rpc must be formatted as `<url>|<store_path>`");
+
+        let inner = R::new(url);
+        let db = sled::open(store_path).expect("valid sled store path");
+
+        CachedRpc { inner, db }
+    }
+
+    async fn get_bootstrap(&self, block_root: &'_ [u8]) -> Result<Bootstrap> {
+        self.inner.get_bootstrap(block_root).await
+    }
+
+    async fn get_updates(&self, period: u64, count: u8) -> Result<Vec<Update>> {
+        let mut merged = Vec::with_capacity(count as usize);
+
+        for i in 0..count as u64 {
+            let target_period = period + i;
+
+            // Always re-fetch and compare the most recently requested period; see `store_if_better`.
+            let is_latest_requested = i + 1 == count as u64;
+            let cached = if is_latest_requested {
+                None
+            } else {
+                self.load(target_period)?
+            };
+
+            let update = match cached {
+                Some(update) => update,
+                None => {
+                    // Propagate a genuine inner-RPC failure; only treat an empty response
+                    // as "nothing there yet" and return the confirmed prefix for that.
+                    let fetched = self.inner.get_updates(target_period, 1).await?;
+                    match fetched.into_iter().next() {
+                        Some(update) => {
+                            self.store_if_better(target_period, &update)?;
+                            update
+                        }
+                        None => break,
+                    }
+                }
+            };
+
+            merged.push(update);
+        }
+
+        Ok(merged)
+    }
+
+    async fn get_finality_update(&self) -> Result<FinalityUpdate> {
+        self.inner.get_finality_update().await
+    }
+
+    async fn get_optimistic_update(&self) -> Result<OptimisticUpdate> {
+        self.inner.get_optimistic_update().await
+    }
+
+    async fn get_block(&self, slot: u64) -> Result<BeaconBlock> {
+        self.inner.get_block(slot).await
+    }
+
+    async fn chain_id(&self) -> Result<u64> {
+        self.inner.chain_id().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::nimbus_rpc::NimbusRpc;
+
+    fn update_with(set_bits: usize, signature_slot: u64) -> Update {
+        let mut update = Update::default();
+        for i in 0..set_bits {
+            update.sync_aggregate.sync_committee_bits[i] = true;
+        }
+        update.signature_slot = signature_slot;
+        update
+    }
+
+    #[test]
+    fn more_participation_wins() {
+        let weak = update_with(300, 10);
+        let strong = update_with(400, 5);
+        assert!(is_better_update(&strong, &weak));
+        assert!(!is_better_update(&weak, &strong));
+    }
+
+    #[test]
+    fn tie_breaks_on_signature_slot() {
+        let earlier = update_with(300, 10);
+        let later = update_with(300, 20);
+        assert!(is_better_update(&later, &earlier));
+        assert!(!is_better_update(&earlier, &later));
+    }
+
+    fn cached_rpc() -> CachedRpc<NimbusRpc> {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        CachedRpc::with_store(NimbusRpc::new("http://localhost"), db)
+    }
+
+    #[test]
+    fn store_if_better_keeps_first_write_when_no_improvement() {
+        let rpc = cached_rpc();
+        rpc.store_if_better(1, &update_with(300, 10)).unwrap();
+        rpc.store_if_better(1, &update_with(100, 20)).unwrap();
+        assert_eq!(rpc.load(1).unwrap().unwrap().signature_slot, 10);
+    }
+
+    #[test]
+    fn store_if_better_replaces_on_improvement() {
+        let rpc = cached_rpc();
+        rpc.store_if_better(1, &update_with(300, 10)).unwrap();
+        rpc.store_if_better(1, &update_with(400, 11)).unwrap();
+        assert_eq!(rpc.load(1).unwrap().unwrap().signature_slot, 11);
+    }
+}
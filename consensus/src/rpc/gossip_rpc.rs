@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eyre::Result;
+use futures::StreamExt;
+use libp2p::gossipsub::{
+    Gossipsub, GossipsubConfigBuilder, GossipsubEvent, IdentTopic, MessageAuthenticity,
+};
+use libp2p::swarm::{Swarm, SwarmEvent};
+use libp2p::{identity, Multiaddr, PeerId};
+use snap::raw::Decoder as SnappyDecoder;
+use ssz_rs::Deserialize as SszDeserialize;
+use tokio::sync::Mutex;
+
+use super::ConsensusRpc;
+use crate::types::*;
+
+const FINALITY_TOPIC: &str = "light_client_finality_update";
+const OPTIMISTIC_TOPIC: &str = "light_client_optimistic_update";
+
+/// Separator between the inner HTTP RPC URL and the comma-separated bootnode multiaddrs
+/// packed into the single `rpc` string.
+const BOOTNODE_SEP: char = '|';
+const BOOTNODE_LIST_SEP: char = ',';
+
+/// Tracks finality and optimistic updates over libp2p gossip. `get_bootstrap`,
+/// `get_updates` and `get_block` aren't gossiped, so they're delegated to an inner
+/// HTTP-backed `ConsensusRpc`.
+pub struct GossipRpc<R: ConsensusRpc> {
+    http: R,
+    finality: Arc<Mutex<Option<FinalityUpdate>>>,
+    optimistic: Arc<Mutex<Option<OptimisticUpdate>>>,
+}
+
+impl<R: ConsensusRpc + Send + Sync> GossipRpc<R> {
+    /// Dials `bootnodes` to join the gossip network.
+    pub fn with_bootnodes(http: R, bootnodes: Vec<Multiaddr>) -> Self {
+        let finality = Arc::new(Mutex::new(None));
+        let optimistic = Arc::new(Mutex::new(None));
+
+        tokio::spawn(run_gossip(finality.clone(), optimistic.clone(), bootnodes));
+
+        GossipRpc {
+            http,
+            finality,
+            optimistic,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: ConsensusRpc + Send + Sync> ConsensusRpc for GossipRpc<R> {
+    fn new(rpc: &str) -> Self {
+        let (http_url, bootnodes) = match rpc.split_once(BOOTNODE_SEP) {
+            Some((url, nodes)) => (url, parse_bootnodes(nodes)),
+            None => (rpc, Vec::new()),
+        };
+
+        if bootnodes.is_empty() {
+            tracing::warn!(
+                "GossipRpc configured without bootnodes; it will not discover any gossip peers"
+            );
+        }
+
+        GossipRpc::with_bootnodes(R::new(http_url), bootnodes)
+    }
+
+    async fn get_bootstrap(&self, block_root: &'_ [u8]) -> Result<Bootstrap> {
+        self.http.get_bootstrap(block_root).await
+    }
+
+    async fn get_updates(&self, period: u64, count: u8) -> Result<Vec<Update>> {
+        self.http.get_updates(period, count).await
+    }
+
+    async fn get_finality_update(&self) -> Result<FinalityUpdate> {
+        self.finality
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| eyre::eyre!("no finality update received over gossip yet"))
+    }
+
+    async fn get_optimistic_update(&self) -> Result<OptimisticUpdate> {
+        self.optimistic
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| eyre::eyre!("no optimistic update received over gossip yet"))
+    }
+
+    async fn get_block(&self, slot: u64) -> Result<BeaconBlock> {
+        self.http.get_block(slot).await
+    }
+
+    async fn chain_id(&self) -> Result<u64> {
+        self.http.chain_id().await
+    }
+}
+
+fn parse_bootnodes(nodes: &str) -> Vec<Multiaddr> {
+    nodes
+        .split(BOOTNODE_LIST_SEP)
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| match s.parse::<Multiaddr>() {
+            Ok(addr) => Some(addr),
+            Err(e) => {
+                tracing::warn!("ignoring invalid bootnode multiaddr `{s}`: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+async fn run_gossip(
+    finality: Arc<Mutex<Option<FinalityUpdate>>>,
+    optimistic: Arc<Mutex<Option<OptimisticUpdate>>>,
+    bootnodes: Vec<Multiaddr>,
+) {
+    let keypair = identity::Keypair::generate_ed25519();
+    let peer_id = PeerId::from(keypair.public());
+
+    let gossipsub_config = GossipsubConfigBuilder::default()
+        .build()
+        .expect("valid gossipsub config");
+
+    let mut gossipsub = Gossipsub::new(MessageAuthenticity::Signed(keypair.clone()), gossipsub_config)
+        .expect("valid gossipsub behaviour");
+
+    let finality_topic = IdentTopic::new(FINALITY_TOPIC);
+    let optimistic_topic = IdentTopic::new(OPTIMISTIC_TOPIC);
+    gossipsub.subscribe(&finality_topic).ok();
+    gossipsub.subscribe(&optimistic_topic).ok();
+
+    let transport = libp2p::development_transport(keypair)
+        .await
+        .expect("valid transport");
+    let mut swarm = Swarm::with_tokio_executor(transport, gossipsub, peer_id);
+
+    swarm
+        .listen_on("/ip4/0.0.0.0/tcp/0".parse().expect("valid multiaddr"))
+        .expect("swarm can listen");
+
+    // Gossipsub only delivers messages from peers we're connected to, so dial every
+    // configured bootnode.
+    for addr in bootnodes {
+        if let Err(e) = swarm.dial(addr.clone()) {
+            tracing::warn!("failed to dial bootnode {addr}: {e}");
+        }
+    }
+
+    let mut finality_slot = 0;
+    let mut optimistic_slot = 0;
+
+    loop {
+        if let SwarmEvent::Behaviour(GossipsubEvent::Message { message, .. }) =
+            swarm.select_next_some().await
+        {
+            if message.topic == finality_topic.hash() {
+                match decode_gossip::<FinalityUpdate>(&message.data) {
+                    Ok(update) if advances_slot(update.signature_slot, finality_slot) => {
+                        finality_slot = update.signature_slot;
+                        *finality.lock().await = Some(update);
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("dropping finality_update gossip message: {e}"),
+                }
+            } else if message.topic == optimistic_topic.hash() {
+                match decode_gossip::<OptimisticUpdate>(&message.data) {
+                    Ok(update) if advances_slot(update.signature_slot, optimistic_slot) => {
+                        optimistic_slot = update.signature_slot;
+                        *optimistic.lock().await = Some(update);
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("dropping optimistic_update gossip message: {e}"),
+                }
+            }
+        }
+    }
+}
+
+/// Rejects re-broadcasts and out-of-order messages so a caller's view of the head can't regress.
+fn advances_slot(new_slot: u64, current_slot: u64) -> bool {
+    new_slot > current_slot
+}
+
+/// Gossip-domain payloads are snappy-compressed ahead of the SSZ encoding.
+fn decode_gossip<T: SszDeserialize>(data: &[u8]) -> Result<T> {
+    let decompressed = SnappyDecoder::new()
+        .decompress_vec(data)
+        .map_err(|e| eyre::eyre!("snappy decompress failed: {e}"))?;
+
+    T::deserialize(&decompressed).map_err(|e| eyre::eyre!("ssz decode failed: {e:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ssz_rs::Serialize as SszSerialize;
+
+    #[test]
+    fn advances_slot_requires_strictly_greater() {
+        assert!(advances_slot(2, 1));
+        assert!(!advances_slot(1, 1));
+        assert!(!advances_slot(1, 2));
+    }
+
+    #[test]
+    fn decode_gossip_round_trips_snappy_ssz() {
+        let update = OptimisticUpdate::default();
+        let mut ssz = Vec::new();
+        update.serialize(&mut ssz).unwrap();
+        let compressed = snap::raw::Encoder::new().compress_vec(&ssz).unwrap();
+
+        let decoded: OptimisticUpdate = decode_gossip(&compressed).unwrap();
+        assert_eq!(decoded, update);
+    }
+
+    #[test]
+    fn decode_gossip_rejects_uncompressed_payload() {
+        let update = OptimisticUpdate::default();
+        let mut ssz = Vec::new();
+        update.serialize(&mut ssz).unwrap();
+
+        assert!(decode_gossip::<OptimisticUpdate>(&ssz).is_err());
+    }
+}
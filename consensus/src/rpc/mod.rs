@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use eyre::Result;
+
+use crate::types::*;
+
+mod cached_rpc;
+mod gossip_rpc;
+mod multi_rpc;
+mod nimbus_rpc;
+
+pub use cached_rpc::CachedRpc;
+pub use gossip_rpc::GossipRpc;
+pub use multi_rpc::MultiRpc;
+pub use nimbus_rpc::NimbusRpc;
+
+#[async_trait]
+pub trait ConsensusRpc {
+    fn new(rpc: &str) -> Self
+    where
+        Self: Sized;
+    async fn get_bootstrap(&self, block_root: &[u8]) -> Result<Bootstrap>;
+    async fn get_updates(&self, period: u64, count: u8) -> Result<Vec<Update>>;
+    async fn get_finality_update(&self) -> Result<FinalityUpdate>;
+    async fn get_optimistic_update(&self) -> Result<OptimisticUpdate>;
+    async fn get_block(&self, slot: u64) -> Result<BeaconBlock>;
+    async fn chain_id(&self) -> Result<u64>;
+}
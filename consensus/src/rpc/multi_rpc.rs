@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use eyre::Result;
+use futures::future::join_all;
+
+use super::ConsensusRpc;
+use crate::types::*;
+
+/// Separator between endpoint URLs packed into the single `rpc` string.
+const ENDPOINT_SEP: char = ',';
+
+/// Fans a request out to several inner `ConsensusRpc`s and only returns once at least
+/// `quorum` of them agree, so a single malicious or faulty provider can't feed the light
+/// client a bogus update.
+pub struct MultiRpc<R: ConsensusRpc> {
+    rpcs: Vec<R>,
+    quorum: usize,
+}
+
+impl<R: ConsensusRpc + Send + Sync> MultiRpc<R> {
+    pub fn with_quorum(rpcs: Vec<R>, quorum: usize) -> Self {
+        MultiRpc { rpcs, quorum }
+    }
+
+    /// Endpoint errors don't count toward quorum but don't abort it either, so a handful
+    /// of down endpoints doesn't block an otherwise healthy quorum.
+    fn pick<T: PartialEq + Clone>(&self, kind: &'static str, results: Vec<Result<T>>) -> Result<T> {
+        let mut groups: Vec<(T, Vec<usize>)> = Vec::new();
+        let mut errored: Vec<usize> = Vec::new();
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(value) => match groups.iter_mut().find(|(v, _)| v == &value) {
+                    Some((_, idxs)) => idxs.push(i),
+                    None => groups.push((value, vec![i])),
+                },
+                Err(_) => errored.push(i),
+            }
+        }
+
+        let winner = groups
+            .iter()
+            .max_by_key(|(_, idxs)| idxs.len())
+            .filter(|(_, idxs)| idxs.len() >= self.quorum)
+            .map(|(value, _)| value.clone());
+
+        winner.ok_or_else(|| {
+            let dissent = groups
+                .iter()
+                .map(|(_, idxs)| format!("endpoints {idxs:?} agreed on one value"))
+                .chain(
+                    (!errored.is_empty())
+                        .then(|| format!("endpoints {errored:?} errored")),
+                )
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            eyre::eyre!(
+                "no quorum of {} reached for `{kind}` across {} endpoints: {dissent}",
+                self.quorum,
+                self.rpcs.len(),
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl<R: ConsensusRpc + Send + Sync> ConsensusRpc for MultiRpc<R> {
+    fn new(rpc: &str) -> Self {
+        let rpcs: Vec<R> = rpc.split(ENDPOINT_SEP).map(R::new).collect();
+        let quorum = rpcs.len() / 2 + 1;
+        MultiRpc { rpcs, quorum }
+    }
+
+    async fn get_bootstrap(&self, block_root: &'_ [u8]) -> Result<Bootstrap> {
+        let results = join_all(self.rpcs.iter().map(|r| r.get_bootstrap(block_root))).await;
+        self.pick("bootstrap", results)
+    }
+
+    async fn get_updates(&self, period: u64, count: u8) -> Result<Vec<Update>> {
+        let results = join_all(self.rpcs.iter().map(|r| r.get_updates(period, count))).await;
+
+        if results.iter().all(|r| r.is_err()) {
+            return Err(eyre::eyre!(
+                "all {} endpoints failed for `updates`",
+                self.rpcs.len()
+            ));
+        }
+
+        // Endpoints can legitimately return fewer than `count` updates (e.g. near the chain
+        // head); merge only over the range every responding endpoint actually covered.
+        let min_len = results
+            .iter()
+            .filter_map(|r| r.as_ref().ok())
+            .map(|updates| updates.len())
+            .min()
+            .unwrap_or(0);
+
+        let mut merged = Vec::with_capacity(min_len);
+        for i in 0..min_len {
+            let per_period: Vec<Result<Update>> = results
+                .iter()
+                .map(|r| match r {
+                    Ok(updates) => updates
+                        .get(i)
+                        .cloned()
+                        .ok_or_else(|| eyre::eyre!("endpoint returned too few updates")),
+                    Err(e) => Err(eyre::eyre!("{e}")),
+                })
+                .collect();
+
+            merged.push(self.pick("updates", per_period)?);
+        }
+
+        Ok(merged)
+    }
+
+    async fn get_finality_update(&self) -> Result<FinalityUpdate> {
+        let results = join_all(self.rpcs.iter().map(|r| r.get_finality_update())).await;
+        self.pick("finality_update", results)
+    }
+
+    async fn get_optimistic_update(&self) -> Result<OptimisticUpdate> {
+        let results = join_all(self.rpcs.iter().map(|r| r.get_optimistic_update())).await;
+        self.pick("optimistic_update", results)
+    }
+
+    async fn get_block(&self, slot: u64) -> Result<BeaconBlock> {
+        let results = join_all(self.rpcs.iter().map(|r| r.get_block(slot))).await;
+        self.pick("blocks", results)
+    }
+
+    async fn chain_id(&self) -> Result<u64> {
+        let results = join_all(self.rpcs.iter().map(|r| r.chain_id())).await;
+        self.pick("spec", results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::nimbus_rpc::NimbusRpc;
+
+    fn rpc(quorum: usize) -> MultiRpc<NimbusRpc> {
+        MultiRpc::with_quorum(Vec::new(), quorum)
+    }
+
+    #[test]
+    fn pick_returns_majority_value() {
+        let m = rpc(2);
+        let results: Vec<Result<u64>> = vec![Ok(1), Ok(1), Ok(2)];
+        assert_eq!(m.pick("x", results).unwrap(), 1);
+    }
+
+    #[test]
+    fn pick_errors_without_quorum() {
+        let m = rpc(3);
+        let results: Vec<Result<u64>> = vec![Ok(1), Ok(1), Ok(2)];
+        assert!(m.pick("x", results).is_err());
+    }
+
+    #[test]
+    fn pick_ignores_endpoint_errors_toward_quorum() {
+        let m = rpc(2);
+        let results: Vec<Result<u64>> = vec![Ok(1), Ok(1), Err(eyre::eyre!("down"))];
+        assert_eq!(m.pick("x", results).unwrap(), 1);
+    }
+
+    #[test]
+    fn pick_all_errors_fails() {
+        let m = rpc(1);
+        let results: Vec<Result<u64>> = vec![Err(eyre::eyre!("a")), Err(eyre::eyre!("b"))];
+        assert!(m.pick("x", results).is_err());
+    }
+}
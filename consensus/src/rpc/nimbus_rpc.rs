@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use eyre::Result;
+use reqwest::Response;
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
+use ssz_rs::{Deserialize as SszDeserialize, Serialize as SszSerialize};
 use std::cmp;
 
 use super::ConsensusRpc;
@@ -9,9 +11,31 @@ use crate::constants::MAX_REQUEST_LIGHT_CLIENT_UPDATES;
 use crate::types::*;
 use common::errors::RpcError;
 
+const ACCEPT_SSZ: &str = "application/octet-stream";
+/// Fork-digest context prefix in front of each item in a `light_client/updates` SSZ response.
+const CONTEXT_LEN: usize = 4;
+
 pub struct NimbusRpc {
     rpc: String,
     client: ClientWithMiddleware,
+    prefer_ssz: bool,
+}
+
+impl NimbusRpc {
+    /// Falls back to JSON if the server doesn't honor the SSZ `Accept` header.
+    pub fn with_ssz(mut self, prefer_ssz: bool) -> Self {
+        self.prefer_ssz = prefer_ssz;
+        self
+    }
+
+    async fn send(&self, req: String, kind: &'static str) -> Result<Response> {
+        let mut builder = self.client.get(req);
+        if self.prefer_ssz {
+            builder = builder.header("Accept", ACCEPT_SSZ);
+        }
+
+        builder.send().await.map_err(|e| RpcError::new(kind, e).into())
+    }
 }
 
 #[async_trait]
@@ -28,6 +52,7 @@ impl ConsensusRpc for NimbusRpc {
         NimbusRpc {
             rpc: rpc.to_string(),
             client,
+            prefer_ssz: false,
         }
     }
 
@@ -38,17 +63,18 @@ impl ConsensusRpc for NimbusRpc {
             self.rpc, root_hex
         );
 
-        let res = self
-            .client
-            .get(req)
-            .send()
-            .await
-            .map_err(|e| RpcError::new("bootstrap", e))?
-            .json::<BootstrapResponse>()
-            .await
-            .map_err(|e| RpcError::new("bootstrap", e))?;
+        let res = self.send(req, "bootstrap").await?;
+        if is_ssz_response(&res) {
+            let bytes = res.bytes().await.map_err(|e| RpcError::new("bootstrap", e))?;
+            Bootstrap::deserialize(&bytes).map_err(|e| RpcError::new("bootstrap", e).into())
+        } else {
+            let res = res
+                .json::<BootstrapResponse>()
+                .await
+                .map_err(|e| RpcError::new("bootstrap", e))?;
 
-        Ok(res.data)
+            Ok(res.data)
+        }
     }
 
     async fn get_updates(&self, period: u64, count: u8) -> Result<Vec<Update>> {
@@ -58,62 +84,73 @@ impl ConsensusRpc for NimbusRpc {
             self.rpc, period, count
         );
 
-        let res = self
-            .client
-            .get(req)
-            .send()
-            .await
-            .map_err(|e| RpcError::new("updates", e))?
-            .json::<UpdateResponse>()
-            .await
-            .map_err(|e| RpcError::new("updates", e))?;
+        let res = self.send(req, "updates").await?;
+        if is_ssz_response(&res) {
+            let bytes = res.bytes().await.map_err(|e| RpcError::new("updates", e))?;
+            decode_ssz_updates(&bytes)
+        } else {
+            let res = res
+                .json::<UpdateResponse>()
+                .await
+                .map_err(|e| RpcError::new("updates", e))?;
 
-        Ok(res.iter().map(|d| d.data.clone()).collect())
+            Ok(res.iter().map(|d| d.data.clone()).collect())
+        }
     }
 
     async fn get_finality_update(&self) -> Result<FinalityUpdate> {
         let req = format!("{}/eth/v1/beacon/light_client/finality_update", self.rpc);
-        let res = self
-            .client
-            .get(req)
-            .send()
-            .await
-            .map_err(|e| RpcError::new("finality_update", e))?
-            .json::<FinalityUpdateResponse>()
-            .await
-            .map_err(|e| RpcError::new("finality_update", e))?;
+        let res = self.send(req, "finality_update").await?;
+        if is_ssz_response(&res) {
+            let bytes = res
+                .bytes()
+                .await
+                .map_err(|e| RpcError::new("finality_update", e))?;
+            FinalityUpdate::deserialize(&bytes).map_err(|e| RpcError::new("finality_update", e).into())
+        } else {
+            let res = res
+                .json::<FinalityUpdateResponse>()
+                .await
+                .map_err(|e| RpcError::new("finality_update", e))?;
 
-        Ok(res.data)
+            Ok(res.data)
+        }
     }
 
     async fn get_optimistic_update(&self) -> Result<OptimisticUpdate> {
         let req = format!("{}/eth/v1/beacon/light_client/optimistic_update", self.rpc);
-        let res = self
-            .client
-            .get(req)
-            .send()
-            .await
-            .map_err(|e| RpcError::new("optimistic_update", e))?
-            .json::<OptimisticUpdateResponse>()
-            .await
-            .map_err(|e| RpcError::new("optimistic_update", e))?;
+        let res = self.send(req, "optimistic_update").await?;
+        if is_ssz_response(&res) {
+            let bytes = res
+                .bytes()
+                .await
+                .map_err(|e| RpcError::new("optimistic_update", e))?;
+            OptimisticUpdate::deserialize(&bytes)
+                .map_err(|e| RpcError::new("optimistic_update", e).into())
+        } else {
+            let res = res
+                .json::<OptimisticUpdateResponse>()
+                .await
+                .map_err(|e| RpcError::new("optimistic_update", e))?;
 
-        Ok(res.data)
+            Ok(res.data)
+        }
     }
 
     async fn get_block(&self, slot: u64) -> Result<BeaconBlock> {
         let req = format!("{}/eth/v2/beacon/blocks/{}", self.rpc, slot);
-        let res = self
-            .client
-            .get(req)
-            .send()
-            .await
-            .map_err(|e| RpcError::new("blocks", e))?
-            .json::<BeaconBlockResponse>()
-            .await
-            .map_err(|e| RpcError::new("blocks", e))?;
+        let res = self.send(req, "blocks").await?;
+        if is_ssz_response(&res) {
+            let bytes = res.bytes().await.map_err(|e| RpcError::new("blocks", e))?;
+            BeaconBlock::deserialize(&bytes).map_err(|e| RpcError::new("blocks", e).into())
+        } else {
+            let res = res
+                .json::<BeaconBlockResponse>()
+                .await
+                .map_err(|e| RpcError::new("blocks", e))?;
 
-        Ok(res.data.message)
+            Ok(res.data.message)
+        }
     }
 
     async fn chain_id(&self) -> Result<u64> {
@@ -132,6 +169,45 @@ impl ConsensusRpc for NimbusRpc {
     }
 }
 
+fn is_ssz_response(res: &Response) -> bool {
+    res.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.starts_with(ACCEPT_SSZ))
+        .unwrap_or(false)
+}
+
+/// Item count comes from the body length, not the caller's `count` — the server can
+/// legitimately return fewer items than requested (e.g. near the chain head).
+fn decode_ssz_updates(bytes: &[u8]) -> Result<Vec<Update>> {
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let item_len = CONTEXT_LEN + update_ssz_len();
+    bytes
+        .chunks(item_len)
+        .map(|chunk| {
+            let body = chunk
+                .get(CONTEXT_LEN..)
+                .ok_or_else(|| eyre::eyre!("truncated light client update in SSZ response"))?;
+
+            Update::deserialize(body).map_err(|e| eyre::eyre!("ssz decode of update: {e:?}"))
+        })
+        .collect()
+}
+
+/// `Update`'s SSZ fields are all fixed-size `Vector`/`Bitvector`s, so every `Update`
+/// serializes to the same number of bytes; measuring a default instance avoids
+/// hard-coding a size constant that would drift if the type changes.
+fn update_ssz_len() -> usize {
+    let mut buf = Vec::new();
+    Update::default()
+        .serialize(&mut buf)
+        .expect("a default Update always serializes");
+    buf.len()
+}
+
 #[derive(serde::Deserialize, Debug)]
 struct BeaconBlockResponse {
     data: BeaconBlockData,
@@ -174,3 +250,41 @@ struct Spec {
     #[serde(rename = "DEPOSIT_NETWORK_ID", deserialize_with = "u64_deserialize")]
     chain_id: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_ssz_len_is_fixed_and_nonzero() {
+        assert_eq!(update_ssz_len(), update_ssz_len());
+        assert!(update_ssz_len() > 0);
+    }
+
+    #[test]
+    fn decode_ssz_updates_empty_body_is_empty() {
+        assert_eq!(decode_ssz_updates(&[]).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn decode_ssz_updates_round_trips_n_items() {
+        let item_len = CONTEXT_LEN + update_ssz_len();
+        let mut bytes = vec![0u8; item_len * 3];
+        for chunk in bytes.chunks_mut(item_len) {
+            let mut buf = Vec::new();
+            Update::default().serialize(&mut buf).unwrap();
+            chunk[CONTEXT_LEN..].copy_from_slice(&buf);
+        }
+
+        let updates = decode_ssz_updates(&bytes).unwrap();
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[0], Update::default());
+    }
+
+    #[test]
+    fn decode_ssz_updates_rejects_truncated_final_chunk() {
+        let item_len = CONTEXT_LEN + update_ssz_len();
+        let bytes = vec![0u8; item_len + CONTEXT_LEN - 1];
+        assert!(decode_ssz_updates(&bytes).is_err());
+    }
+}
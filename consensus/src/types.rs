@@ -0,0 +1,86 @@
+use serde::{Deserialize, Deserializer};
+use ssz_rs::{Bitvector, Vector};
+
+/// Sync committee size and merkle-branch depths from the Altair light client spec. These
+/// make the corresponding SSZ fields fixed-size `Vector`/`Bitvector`s rather than `List`s,
+/// which is what lets `NimbusRpc` compute an `Update`'s encoded length from its type alone.
+pub const SYNC_COMMITTEE_SIZE: usize = 512;
+pub const SYNC_COMMITTEE_DEPTH: usize = 5;
+pub const FINALIZED_ROOT_DEPTH: usize = 6;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct Bootstrap {
+    pub header: BeaconBlockHeader,
+    pub current_sync_committee: SyncCommittee,
+    pub current_sync_committee_branch: Vector<Bytes32, SYNC_COMMITTEE_DEPTH>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct Update {
+    pub attested_header: BeaconBlockHeader,
+    pub next_sync_committee: SyncCommittee,
+    pub next_sync_committee_branch: Vector<Bytes32, SYNC_COMMITTEE_DEPTH>,
+    pub finalized_header: BeaconBlockHeader,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_DEPTH>,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: u64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct FinalityUpdate {
+    pub attested_header: BeaconBlockHeader,
+    pub finalized_header: BeaconBlockHeader,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_DEPTH>,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: u64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct OptimisticUpdate {
+    pub attested_header: BeaconBlockHeader,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: u64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct BeaconBlock {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: Bytes32,
+    pub state_root: Bytes32,
+    pub body_root: Bytes32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: Bytes32,
+    pub state_root: Bytes32,
+    pub body_root: Bytes32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct SyncCommittee {
+    pub pubkeys: Vector<BlsPubkey, SYNC_COMMITTEE_SIZE>,
+    pub aggregate_pubkey: BlsPubkey,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ssz_rs::Serialize, ssz_rs::Deserialize)]
+pub struct SyncAggregate {
+    pub sync_committee_bits: Bitvector<SYNC_COMMITTEE_SIZE>,
+    pub sync_committee_signature: BlsSignature,
+}
+
+pub type Bytes32 = [u8; 32];
+pub type BlsPubkey = [u8; 48];
+pub type BlsSignature = [u8; 96];
+
+/// `/eth/v1/config/spec` sends chain IDs as decimal strings.
+pub fn u64_deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+}